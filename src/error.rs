@@ -54,8 +54,13 @@ pub enum AppError {
     Conflict(String),
 
     /// 422 Unprocessable Entity
-    #[error("Validation error: {0}")]
-    ValidationError(String),
+    /// `details` carries a field name -> failed constraints map when the
+    /// error originated from `validator`-driven request validation.
+    #[error("Validation error: {message}")]
+    ValidationError {
+        message: String,
+        details: Option<serde_json::Value>,
+    },
 
     /// 500 Internal Server Error
     /// Can wrap anyhow::Error to preserve error chains
@@ -76,12 +81,16 @@ pub enum AppError {
 }
 
 /// Error response structure
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, utoipa::ToSchema)]
 pub struct ErrorResponse {
     pub error: String,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<serde_json::Value>,
+    /// Correlation id of the request that produced this error, for tracing
+    /// it back through server logs. See `middleware::request_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 impl AppError {
@@ -93,7 +102,7 @@ impl AppError {
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::Conflict(_) => StatusCode::CONFLICT,
-            AppError::ValidationError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::ValidationError { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::Config(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -109,7 +118,7 @@ impl AppError {
             AppError::Forbidden(_) => "FORBIDDEN",
             AppError::NotFound(_) => "NOT_FOUND",
             AppError::Conflict(_) => "CONFLICT",
-            AppError::ValidationError(_) => "VALIDATION_ERROR",
+            AppError::ValidationError { .. } => "VALIDATION_ERROR",
             AppError::Internal(_) => "INTERNAL_ERROR",
             AppError::Database(_) => "DATABASE_ERROR",
             AppError::Config(_) => "CONFIG_ERROR",
@@ -117,15 +126,17 @@ impl AppError {
         }
     }
 
-    /// Log the error appropriately
+    /// Log the error appropriately, tagging it with the active request id
+    /// (if any) so it can be correlated with the request that caused it.
     pub fn log_error(&self) {
         let status = self.status_code();
+        let request_id = crate::middleware::current_request_id().unwrap_or_default();
         if status.is_server_error() {
-            error!("Server error: {}", self);
+            error!(request_id, "Server error: {}", self);
         } else if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
-            tracing::warn!("Auth error: {}", self);
+            tracing::warn!(request_id, "Auth error: {}", self);
         } else {
-            tracing::debug!("Client error: {}", self);
+            tracing::debug!(request_id, "Client error: {}", self);
         }
     }
 }
@@ -136,10 +147,15 @@ impl IntoResponse for AppError {
         self.log_error();
 
         let status = self.status_code();
+        let details = match &self {
+            AppError::ValidationError { details, .. } => details.clone(),
+            _ => None,
+        };
         let error_response = ErrorResponse {
             error: self.error_code().to_string(),
             message: self.to_string(),
-            details: None,
+            details,
+            request_id: crate::middleware::current_request_id(),
         };
 
         (status, Json(error_response)).into_response()
@@ -150,23 +166,46 @@ impl IntoResponse for AppError {
 // Note: serde_json::Error and config::ConfigError are handled via #[source] attribute above
 // anyhow::Error is handled via #[from] attribute above
 
-// Database error conversions (for future SQLx integration)
-// Uncomment when SQLx is added:
-// impl From<sqlx::Error> for AppError {
-//     fn from(err: sqlx::Error) -> Self {
-//         match err {
-//             sqlx::Error::RowNotFound => AppError::NotFound("Resource not found".to_string()),
-//             sqlx::Error::Database(db_err) => {
-//                 if db_err.code().as_deref() == Some("23505") {
-//                     AppError::Conflict("Duplicate entry".to_string())
-//                 } else {
-//                     AppError::Database(db_err.to_string())
-//                 }
-//             }
-//             _ => AppError::Database(err.to_string()),
-//         }
-//     }
-// }
+/// Maps a SeaORM error into the matching `AppError` variant, inspecting the
+/// underlying SQLSTATE where one is available so that e.g. a unique-email
+/// collision surfaces as a 409 instead of an opaque 500.
+impl From<sea_orm::DbErr> for AppError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        use sea_orm::{DbErr, RuntimeErr};
+
+        match &err {
+            DbErr::RecordNotFound(_) => AppError::NotFound("Resource not found".to_string()),
+            DbErr::Query(RuntimeErr::SqlxError(sqlx_err))
+            | DbErr::Exec(RuntimeErr::SqlxError(sqlx_err)) => {
+                app_error_from_sqlx(sqlx_err).unwrap_or_else(|| AppError::Database(err.to_string()))
+            }
+            _ => AppError::Database(err.to_string()),
+        }
+    }
+}
+
+/// Inspects a SQLx driver error's SQLSTATE and maps common Postgres
+/// constraint-violation codes to the matching `AppError` variant.
+fn app_error_from_sqlx(err: &sqlx::Error) -> Option<AppError> {
+    let db_err = err.as_database_error()?;
+    app_error_for_sqlstate(db_err.code().as_deref(), db_err.message())
+}
+
+/// Maps a Postgres SQLSTATE to the matching `AppError` variant. Split out
+/// from `app_error_from_sqlx` so the mapping itself can be unit-tested
+/// without constructing a real `sqlx::Error`.
+fn app_error_for_sqlstate(code: Option<&str>, message: &str) -> Option<AppError> {
+    match code? {
+        "23505" => Some(AppError::Conflict(
+            "A record with this value already exists".to_string(),
+        )),
+        "23503" => Some(AppError::BadRequest(
+            "Referenced record does not exist".to_string(),
+        )),
+        "23502" | "23514" => Some(AppError::validation(message.to_string())),
+        _ => None,
+    }
+}
 
 /// Result type alias for convenience
 pub type AppResult<T> = Result<T, AppError>;
@@ -216,4 +255,60 @@ impl AppError {
     pub fn internal(msg: impl Into<String>) -> Self {
         AppError::Internal(anyhow::anyhow!(msg.into()))
     }
+
+    /// Create a validation error with a flat message and no field details.
+    pub fn validation(msg: impl Into<String>) -> Self {
+        AppError::ValidationError {
+            message: msg.into(),
+            details: None,
+        }
+    }
+
+    /// Create a validation error carrying a structured field-error map,
+    /// surfaced to clients via `ErrorResponse.details`.
+    pub fn validation_with_details(msg: impl Into<String>, details: serde_json::Value) -> Self {
+        AppError::ValidationError {
+            message: msg.into(),
+            details: Some(details),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_unique_violation_to_conflict() {
+        let err = app_error_for_sqlstate(Some("23505"), "duplicate key value").unwrap();
+        assert_eq!(err.status_code(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn maps_foreign_key_violation_to_bad_request() {
+        let err = app_error_for_sqlstate(Some("23503"), "violates foreign key constraint").unwrap();
+        assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn maps_not_null_violation_to_validation_error() {
+        let err = app_error_for_sqlstate(Some("23502"), "null value in column").unwrap();
+        assert_eq!(err.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn maps_check_violation_to_validation_error() {
+        let err = app_error_for_sqlstate(Some("23514"), "check constraint failed").unwrap();
+        assert_eq!(err.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn unmapped_sqlstate_returns_none() {
+        assert!(app_error_for_sqlstate(Some("42601"), "syntax error").is_none());
+    }
+
+    #[test]
+    fn missing_sqlstate_returns_none() {
+        assert!(app_error_for_sqlstate(None, "unknown").is_none());
+    }
 }
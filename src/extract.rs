@@ -0,0 +1,85 @@
+use axum::{
+    extract::{FromRequest, Request},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use validator::Validate;
+
+use crate::error::AppError;
+
+/// Drop-in replacement for `axum::Json` that also runs `validator::Validate`
+/// on the deserialized body. A failing constraint produces an
+/// `AppError::ValidationError` whose `details` is a field name -> failed
+/// constraint messages map (see `field_errors_to_json`), instead of a flat
+/// error string.
+pub struct ValidatedJson<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|rejection| AppError::BadRequest(rejection.body_text()))?;
+
+        value
+            .validate()
+            .map_err(|errors| AppError::validation_with_details("request validation failed", field_errors_to_json(&errors)))?;
+
+        Ok(ValidatedJson(value))
+    }
+}
+
+/// Flattens `validator`'s `ValidationErrors` into `{ field: [messages] }`
+/// JSON, preferring each error's custom `message` (e.g. "must be at least 8
+/// characters") over its internal `code` (e.g. "length") so clients see the
+/// human-readable text configured on the DTO's `#[validate(...)]` attributes.
+fn field_errors_to_json(errors: &validator::ValidationErrors) -> Value {
+    let fields = errors
+        .field_errors()
+        .iter()
+        .map(|(field, errs)| {
+            let messages: Vec<String> = errs
+                .iter()
+                .map(|e| e.message.as_deref().unwrap_or(&e.code).to_string())
+                .collect();
+            (field.to_string(), messages)
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+
+    json!(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use validator::ValidationError;
+
+    use super::*;
+
+    #[test]
+    fn prefers_custom_message_over_code() {
+        let mut errors = validator::ValidationErrors::new();
+        let mut err = ValidationError::new("email");
+        err.message = Some(Cow::Borrowed("must be a valid email address"));
+        errors.add("email", err);
+
+        let json = field_errors_to_json(&errors);
+        assert_eq!(json["email"], serde_json::json!(["must be a valid email address"]));
+    }
+
+    #[test]
+    fn falls_back_to_code_when_no_message() {
+        let mut errors = validator::ValidationErrors::new();
+        errors.add("name", ValidationError::new("length"));
+
+        let json = field_errors_to_json(&errors);
+        assert_eq!(json["name"], serde_json::json!(["length"]));
+    }
+}
@@ -1,11 +1,15 @@
 use anyhow::Result;
 use tokio::signal;
-use tracing::{info, Level};
+use tracing::{error, info, Level};
 use tracing_subscriber::fmt;
 
 mod app;
 mod modules;
 mod config;
+mod error;
+mod extract;
+mod middleware;
+mod openapi;
 
 use app::rust_saas;
 use config::AppConfig;
@@ -17,11 +21,17 @@ async fn main() -> Result<()> {
         .with_max_level(Level::INFO)
         .init();
 
-    // Load configuration from environment variables
-    let config = AppConfig::from_env();
+    // Layer config.toml + environment variables, failing fast on error
+    let config = match AppConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
     let addr = config.server_addr();
 
-    let app = rust_saas();
+    let app = middleware::apply(rust_saas(), &config);
 
     info!("🚀 Server starting on http://{}", addr);
 
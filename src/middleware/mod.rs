@@ -0,0 +1,55 @@
+pub mod request_id;
+
+use axum::{http::Request, middleware::from_fn, Router};
+use tower_http::{
+    compression::CompressionLayer, cors::AllowOrigin, cors::Any, cors::CorsLayer,
+    decompression::RequestDecompressionLayer, trace::TraceLayer,
+};
+use tracing::info_span;
+
+use crate::config::AppConfig;
+
+pub use request_id::current as current_request_id;
+
+/// Composes the production middleware stack onto `router`: request-id
+/// generation/propagation, CORS, gzip-compressed responses and gzip-decoded
+/// request bodies, and a tracing span per request, driven by `config`.
+/// `Router::layer` nests outside-in, so the request-id layer is added last
+/// (outermost) — every other layer, and the handler itself, sees the
+/// request already tagged with its id.
+pub fn apply(router: Router, config: &AppConfig) -> Router {
+    router
+        .layer(
+            TraceLayer::new_for_http().make_span_with(|req: &Request<axum::body::Body>| {
+                let request_id = req
+                    .extensions()
+                    .get::<String>()
+                    .cloned()
+                    .unwrap_or_default();
+                info_span!(
+                    "http_request",
+                    method = %req.method(),
+                    path = %req.uri().path(),
+                    request_id,
+                )
+            }),
+        )
+        .layer(RequestDecompressionLayer::new())
+        .layer(CompressionLayer::new())
+        .layer(cors_layer(config))
+        .layer(from_fn(request_id::request_id_middleware))
+}
+
+fn cors_layer(config: &AppConfig) -> CorsLayer {
+    let origins: Vec<_> = config
+        .cors
+        .allowed_origins
+        .iter()
+        .filter_map(|origin| origin.parse().ok())
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods(Any)
+        .allow_headers(Any)
+}
@@ -0,0 +1,42 @@
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use uuid::Uuid;
+
+/// Response/request header carrying the per-request correlation id.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    /// The current request's correlation id. Set by `request_id_middleware`
+    /// for the duration of the request so that code with no access to the
+    /// request (e.g. `AppError::into_response`) can still attach it to logs
+    /// and error responses.
+    static REQUEST_ID: String;
+}
+
+/// Generates a UUID per request (reusing an inbound `x-request-id` if the
+/// caller already set one), scopes it as a task-local for the request, and
+/// echoes it back on the response.
+pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    req.extensions_mut().insert(request_id.clone());
+
+    let header_value = HeaderValue::from_str(&request_id).ok();
+    let mut response = REQUEST_ID.scope(request_id, next.run(req)).await;
+
+    if let Some(value) = header_value {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
+
+/// Returns the current request's correlation id, if called from within a
+/// request handled by `request_id_middleware`.
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
@@ -0,0 +1,49 @@
+use axum::{extract::FromRequestParts, http::request::Parts};
+use axum_extra::extract::cookie::CookieJar;
+use axum_extra::headers::{authorization::Bearer, Authorization};
+use axum_extra::TypedHeader;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+use super::jwt::verify_token;
+
+/// Axum extractor that guards a route behind a valid session JWT.
+///
+/// The token is read from the `Authorization: Bearer` header first, falling
+/// back to the `auth_token` cookie. Missing, malformed or expired tokens are
+/// rejected as `AppError::Unauthorized`.
+pub struct RequireUser {
+    pub user_id: Uuid,
+}
+
+impl FromRequestParts<AppState> for RequireUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = extract_token(parts).await?;
+        let claims = verify_token(&token, &state.config.auth.jwt_secret)
+            .map_err(|_| AppError::Unauthorized("invalid or expired token".to_string()))?;
+
+        Ok(RequireUser {
+            user_id: claims.sub,
+        })
+    }
+}
+
+async fn extract_token(parts: &mut Parts) -> Result<String, AppError> {
+    if let Ok(TypedHeader(Authorization(bearer))) =
+        TypedHeader::<Authorization<Bearer>>::from_request_parts(parts, &()).await
+    {
+        return Ok(bearer.token().to_string());
+    }
+
+    CookieJar::from_headers(&parts.headers)
+        .get("auth_token")
+        .map(|cookie| cookie.value().to_string())
+        .ok_or_else(|| AppError::Unauthorized("missing authentication token".to_string()))
+}
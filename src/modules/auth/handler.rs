@@ -0,0 +1,125 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+use time::Duration as CookieDuration;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::error::{AppError, AppResult};
+use crate::extract::ValidatedJson;
+use crate::modules::users::entity::{self, Entity as Users};
+use crate::state::AppState;
+
+use super::jwt::mint_token;
+use super::password::{dummy_hash, hash_password, verify_password};
+
+#[derive(serde::Deserialize, Validate)]
+pub struct RegisterRequest {
+    #[validate(email(message = "must be a valid email address"))]
+    pub email: String,
+    #[validate(length(min = 1, max = 100, message = "must be 1-100 characters"))]
+    pub name: String,
+    #[validate(length(min = 8, message = "must be at least 8 characters"))]
+    pub password: String,
+}
+
+#[derive(serde::Deserialize, Validate)]
+pub struct LoginRequest {
+    #[validate(email(message = "must be a valid email address"))]
+    pub email: String,
+    #[validate(length(min = 1, message = "password is required"))]
+    pub password: String,
+}
+
+#[derive(serde::Serialize)]
+pub struct AuthResponse {
+    pub user_id: Uuid,
+    pub token: String,
+}
+
+/// POST /api/auth/register
+pub async fn register(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    ValidatedJson(payload): ValidatedJson<RegisterRequest>,
+) -> AppResult<impl IntoResponse> {
+    let password_hash = hash_password(&payload.password).await?;
+
+    let user = entity::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        email: Set(payload.email),
+        name: Set(payload.name),
+        password_hash: Set(password_hash),
+        is_active: Set(true),
+        created_at: Set(chrono::Utc::now().fixed_offset()),
+        updated_at: Set(chrono::Utc::now().fixed_offset()),
+    };
+
+    let user = user.insert(&state.db).await.map_err(AppError::from)?;
+    let (jar, token) = start_session(jar, user.id, &state)?;
+
+    Ok((
+        StatusCode::CREATED,
+        jar,
+        Json(AuthResponse {
+            user_id: user.id,
+            token,
+        }),
+    ))
+}
+
+/// POST /api/auth/login
+///
+/// Always runs an Argon2 verification, even when the email is unknown (see
+/// `password::dummy_hash`), so a response's latency can't be used to tell
+/// an unregistered email apart from a registered one with the wrong
+/// password.
+pub async fn login(
+    State(state): State<AppState>,
+    jar: CookieJar,
+    ValidatedJson(payload): ValidatedJson<LoginRequest>,
+) -> AppResult<impl IntoResponse> {
+    let user = Users::find()
+        .filter(entity::Column::Email.eq(payload.email))
+        .one(&state.db)
+        .await
+        .map_err(AppError::from)?;
+
+    let Some(user) = user else {
+        let _ = verify_password(&payload.password, dummy_hash().await?).await;
+        return Err(AppError::Unauthorized("invalid email or password".to_string()));
+    };
+
+    verify_password(&payload.password, &user.password_hash).await?;
+
+    let (jar, token) = start_session(jar, user.id, &state)?;
+
+    Ok((
+        jar,
+        Json(AuthResponse {
+            user_id: user.id,
+            token,
+        }),
+    ))
+}
+
+/// Mints a session JWT for `user_id` and attaches it to `jar` as an
+/// HttpOnly/SameSite cookie, returning both the updated jar and the raw
+/// token (for clients that prefer the `Authorization: Bearer` header).
+fn start_session(jar: CookieJar, user_id: Uuid, state: &AppState) -> AppResult<(CookieJar, String)> {
+    let token = mint_token(
+        user_id,
+        &state.config.auth.jwt_secret,
+        state.config.auth.jwt_ttl_seconds,
+    )
+    .map_err(|e| AppError::internal_with_context(e.into(), "failed to mint session token".to_string()))?;
+
+    let cookie = Cookie::build(("auth_token", token.clone()))
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .path("/")
+        .max_age(CookieDuration::seconds(state.config.auth.jwt_ttl_seconds))
+        .build();
+
+    Ok((jar.add(cookie), token))
+}
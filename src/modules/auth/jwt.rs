@@ -0,0 +1,41 @@
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Claims carried by the session JWT.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    /// Subject: the authenticated user's id.
+    pub sub: Uuid,
+    /// Issued-at, Unix timestamp in seconds.
+    pub iat: i64,
+    /// Expiry, Unix timestamp in seconds.
+    pub exp: i64,
+}
+
+/// Mints a signed HS256 JWT for `user_id`, expiring `ttl_seconds` from now.
+pub fn mint_token(user_id: Uuid, secret: &str, ttl_seconds: i64) -> jsonwebtoken::errors::Result<String> {
+    let now = Utc::now();
+    let claims = Claims {
+        sub: user_id,
+        iat: now.timestamp(),
+        exp: (now + Duration::seconds(ttl_seconds)).timestamp(),
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+/// Verifies a JWT's signature and expiry, returning its claims.
+pub fn verify_token(token: &str, secret: &str) -> jsonwebtoken::errors::Result<Claims> {
+    let data = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )?;
+    Ok(data.claims)
+}
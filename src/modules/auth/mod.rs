@@ -0,0 +1,7 @@
+pub mod extractor;
+pub mod handler;
+pub mod jwt;
+pub(crate) mod password;
+pub mod routes;
+
+pub use extractor::RequireUser;
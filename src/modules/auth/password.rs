@@ -0,0 +1,62 @@
+use std::sync::OnceLock;
+
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+
+use crate::error::{AppError, AppResult};
+
+/// Hashes `password` into an Argon2id PHC string with a fresh random salt.
+///
+/// Argon2 is deliberately CPU-heavy, so the actual hashing runs on the
+/// blocking thread pool via `spawn_blocking` instead of stalling the async
+/// worker thread handling this request.
+pub(crate) async fn hash_password(password: &str) -> AppResult<String> {
+    let password = password.to_string();
+    tokio::task::spawn_blocking(move || hash_password_blocking(&password))
+        .await
+        .map_err(|e| AppError::internal(format!("password hashing task panicked: {e}")))?
+}
+
+/// Verifies `password` against a stored Argon2id PHC `hash` in constant
+/// time, off the async worker thread (see `hash_password`).
+pub(crate) async fn verify_password(password: &str, hash: &str) -> AppResult<()> {
+    let password = password.to_string();
+    let hash = hash.to_string();
+    tokio::task::spawn_blocking(move || verify_password_blocking(&password, &hash))
+        .await
+        .map_err(|e| AppError::internal(format!("password verification task panicked: {e}")))?
+}
+
+/// A PHC hash with no corresponding user, for `login` to verify against on
+/// the unknown-email path so it pays the same Argon2 cost as a real login
+/// attempt. Without this, an unknown email would return instantly while a
+/// known one spends tens of milliseconds in Argon2, letting an attacker
+/// enumerate registered emails by timing the response.
+pub(crate) async fn dummy_hash() -> AppResult<&'static str> {
+    static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+    if let Some(hash) = DUMMY_HASH.get() {
+        return Ok(hash);
+    }
+
+    let hash = hash_password("correct-horse-battery-staple").await?;
+    Ok(DUMMY_HASH.get_or_init(|| hash))
+}
+
+fn hash_password_blocking(password: &str) -> AppResult<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AppError::internal(format!("failed to hash password: {e}")))
+}
+
+fn verify_password_blocking(password: &str, hash: &str) -> AppResult<()> {
+    let parsed_hash = PasswordHash::new(hash)
+        .map_err(|e| AppError::internal(format!("stored password hash is invalid: {e}")))?;
+
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .map_err(|_| AppError::Unauthorized("invalid email or password".to_string()))
+}
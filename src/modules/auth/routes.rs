@@ -0,0 +1,12 @@
+use axum::{routing::post, Router};
+
+use crate::state::AppState;
+
+use super::handler;
+
+/// Authentication routes
+pub fn auth_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/auth/register", post(handler::register))
+        .route("/api/auth/login", post(handler::login))
+}
@@ -2,12 +2,24 @@ use axum::{http::StatusCode, response::IntoResponse};
 use crate::error::{AppError, AppResult};
 
 /// Root endpoint
+#[utoipa::path(
+    get,
+    path = "/",
+    responses((status = 200, description = "Service banner")),
+    tag = "health",
+)]
 pub async fn root() -> impl IntoResponse {
     (StatusCode::OK, "Rust SaaS Backend API")
 }
 
 /// Health check endpoint
 /// Returns 200 OK if the service is healthy
+#[utoipa::path(
+    get,
+    path = "/health",
+    responses((status = 200, description = "Service is healthy")),
+    tag = "health",
+)]
 pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, "OK")
 }
@@ -17,7 +29,7 @@ pub async fn health_check() -> impl IntoResponse {
 /// Since AppError implements IntoResponse, we can return it directly
 pub async fn example_error() -> AppError {
     // Example: Return a validation error
-    AppError::ValidationError("Example validation error".to_string())
+    AppError::validation("Example validation error")
 }
 
 /// Example endpoint demonstrating success response
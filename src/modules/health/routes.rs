@@ -1,8 +1,12 @@
 use axum::{routing::get, Router};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::openapi::ApiDoc;
 
 use super::handler;
 
-/// Health check routes
+/// Health check routes, plus the generated OpenAPI document and Swagger UI.
 pub fn health_routes() -> Router {
     Router::new()
         .route("/health", get(handler::health_check))
@@ -11,4 +15,5 @@ pub fn health_routes() -> Router {
         .route("/example/success", get(handler::example_success))
         .route("/example/error", get(handler::example_error))
         .route("/example/result", get(handler::example_result))
+        .merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", ApiDoc::openapi()))
 }
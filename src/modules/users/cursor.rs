@@ -0,0 +1,70 @@
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use sqids::Sqids;
+use uuid::Uuid;
+
+/// Packs a `(created_at, id)` keyset position into a short, URL-safe,
+/// non-sequential cursor string, so clients can page through `list_users`
+/// without being able to guess or enumerate offsets.
+pub fn encode(created_at: DateTime<FixedOffset>, id: Uuid) -> String {
+    let (id_hi, id_lo) = split_uuid(id);
+    Sqids::default()
+        .encode(&[created_at.timestamp_micros() as u64, id_hi, id_lo])
+        .expect("cursor values always fit sqids' u64 id space")
+}
+
+/// Reverses `encode`, returning `None` for a cursor that does not decode to
+/// the three values `encode` packs. An altered cursor that still decodes to
+/// three values is not detected and yields whatever position they encode.
+pub fn decode(cursor: &str) -> Option<(DateTime<FixedOffset>, Uuid)> {
+    let values = Sqids::default().decode(cursor);
+    let [micros, id_hi, id_lo] = values[..] else {
+        return None;
+    };
+
+    let created_at = Utc.timestamp_micros(micros as i64).single()?.fixed_offset();
+    Some((created_at, join_uuid(id_hi, id_lo)))
+}
+
+fn split_uuid(id: Uuid) -> (u64, u64) {
+    let bits = id.as_u128();
+    ((bits >> 64) as u64, bits as u64)
+}
+
+fn join_uuid(hi: u64, lo: u64) -> Uuid {
+    Uuid::from_u128(((hi as u128) << 64) | lo as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_created_at_and_id() {
+        let created_at = Utc.timestamp_micros(1_700_000_000_000_000).unwrap().fixed_offset();
+        let id = Uuid::new_v4();
+
+        let cursor = encode(created_at, id);
+        let (decoded_created_at, decoded_id) = decode(&cursor).expect("cursor should decode");
+
+        assert_eq!(decoded_created_at, created_at);
+        assert_eq!(decoded_id, id);
+    }
+
+    #[test]
+    fn round_trips_nil_uuid() {
+        let created_at = Utc.timestamp_micros(0).unwrap().fixed_offset();
+        let id = Uuid::nil();
+
+        let cursor = encode(created_at, id);
+        let (decoded_created_at, decoded_id) = decode(&cursor).expect("cursor should decode");
+
+        assert_eq!(decoded_created_at, created_at);
+        assert_eq!(decoded_id, id);
+    }
+
+    #[test]
+    fn rejects_garbage_cursor() {
+        assert!(decode("not-a-real-cursor").is_none());
+        assert!(decode("").is_none());
+    }
+}
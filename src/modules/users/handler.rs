@@ -1,32 +1,48 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+use sea_orm::{ActiveModelTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set};
+use utoipa::ToSchema;
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::error::{AppError, AppResult};
+use crate::error::{AppError, AppResult, ErrorResponse};
+use crate::extract::ValidatedJson;
+use crate::modules::auth::password::hash_password;
+use crate::modules::auth::RequireUser;
 use crate::state::AppState;
 
+use super::cursor;
 use super::entity::{self, Entity as Users};
 
-#[derive(serde::Deserialize)]
+/// Default page size for `GET /api/users` when `?limit=` is omitted.
+const DEFAULT_PAGE_LIMIT: u64 = 20;
+/// Largest page size `GET /api/users` will honor, regardless of `?limit=`.
+const MAX_PAGE_LIMIT: u64 = 100;
+
+#[derive(serde::Deserialize, Validate, ToSchema)]
 pub struct CreateUserRequest {
+    #[validate(email(message = "must be a valid email address"))]
     pub email: String,
+    #[validate(length(min = 1, max = 100, message = "must be 1-100 characters"))]
     pub name: String,
+    #[validate(length(min = 8, message = "must be at least 8 characters"))]
     pub password: String,
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, Validate, ToSchema)]
 pub struct UpdateUserRequest {
+    #[validate(email(message = "must be a valid email address"))]
     pub email: Option<String>,
+    #[validate(length(min = 1, max = 100, message = "must be 1-100 characters"))]
     pub name: Option<String>,
     pub is_active: Option<bool>,
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, ToSchema)]
 pub struct UserResponse {
     pub id: Uuid,
     pub email: String,
@@ -49,17 +65,50 @@ impl From<entity::Model> for UserResponse {
     }
 }
 
+#[derive(serde::Deserialize, ToSchema)]
+pub struct ListUsersQuery {
+    /// Max rows to return (default 20, clamped to 100).
+    pub limit: Option<u64>,
+    /// Opaque cursor from a previous response's `next_cursor`.
+    pub cursor: Option<String>,
+}
+
+/// A keyset-paginated page of results.
+#[derive(serde::Serialize, ToSchema)]
+#[aliases(UserPage = PaginatedResponse<UserResponse>)]
+pub struct PaginatedResponse<T> {
+    pub data: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
 /// POST /api/users
+///
+/// Admin-style user creation: unlike `/api/auth/register`, this does not
+/// mint a session for the caller, so it requires an existing one.
+#[utoipa::path(
+    post,
+    path = "/api/users",
+    request_body = CreateUserRequest,
+    responses(
+        (status = 201, description = "User created", body = UserResponse),
+        (status = 401, description = "Missing or invalid session", body = ErrorResponse),
+        (status = 409, description = "Email already registered", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse),
+    ),
+    tag = "users",
+)]
 pub async fn create_user(
     State(state): State<AppState>,
-    Json(payload): Json<CreateUserRequest>,
+    _user: RequireUser,
+    ValidatedJson(payload): ValidatedJson<CreateUserRequest>,
 ) -> AppResult<impl IntoResponse> {
-    // TODO: Hash password with bcrypt/argon2 before storing
+    let password_hash = hash_password(&payload.password).await?;
     let user = entity::ActiveModel {
         id: Set(Uuid::new_v4()),
         email: Set(payload.email),
         name: Set(payload.name),
-        password_hash: Set(payload.password),
+        password_hash: Set(password_hash),
         is_active: Set(true),
         created_at: Set(chrono::Utc::now().fixed_offset()),
         updated_at: Set(chrono::Utc::now().fixed_offset()),
@@ -70,16 +119,89 @@ pub async fn create_user(
 }
 
 /// GET /api/users
-pub async fn list_users(State(state): State<AppState>) -> AppResult<impl IntoResponse> {
-    let users = Users::find().all(&state.db).await.map_err(AppError::from)?;
-    let responses: Vec<UserResponse> = users.into_iter().map(UserResponse::from).collect();
-    Ok(Json(responses))
+#[utoipa::path(
+    get,
+    path = "/api/users",
+    params(
+        ("limit" = Option<u64>, Query, description = "Max rows to return (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "Opaque cursor from a previous response"),
+    ),
+    responses(
+        (status = 200, description = "Page of users", body = UserPage),
+        (status = 400, description = "Undecodable cursor", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid session", body = ErrorResponse),
+    ),
+    tag = "users",
+)]
+pub async fn list_users(
+    State(state): State<AppState>,
+    Query(query): Query<ListUsersQuery>,
+    _user: RequireUser,
+) -> AppResult<impl IntoResponse> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_LIMIT)
+        .clamp(1, MAX_PAGE_LIMIT);
+
+    let mut select = Users::find()
+        .order_by_asc(entity::Column::CreatedAt)
+        .order_by_asc(entity::Column::Id);
+
+    if let Some(cursor) = query.cursor.as_deref() {
+        let (created_at, id) = cursor::decode(cursor)
+            .ok_or_else(|| AppError::BadRequest("invalid pagination cursor".to_string()))?;
+
+        select = select.filter(
+            Condition::any()
+                .add(entity::Column::CreatedAt.gt(created_at))
+                .add(
+                    Condition::all()
+                        .add(entity::Column::CreatedAt.eq(created_at))
+                        .add(entity::Column::Id.gt(id)),
+                ),
+        );
+    }
+
+    let mut rows = select
+        .limit(limit + 1)
+        .all(&state.db)
+        .await
+        .map_err(AppError::from)?;
+
+    let has_more = rows.len() as u64 > limit;
+    if has_more {
+        rows.truncate(limit as usize);
+    }
+
+    let next_cursor = has_more
+        .then(|| rows.last().map(|user| cursor::encode(user.created_at, user.id)))
+        .flatten();
+
+    let data = rows.into_iter().map(UserResponse::from).collect();
+
+    Ok(Json(PaginatedResponse {
+        data,
+        next_cursor,
+        has_more,
+    }))
 }
 
 /// GET /api/users/:id
+#[utoipa::path(
+    get,
+    path = "/api/users/{id}",
+    params(("id" = Uuid, Path, description = "User id")),
+    responses(
+        (status = 200, description = "User found", body = UserResponse),
+        (status = 401, description = "Missing or invalid session", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+    ),
+    tag = "users",
+)]
 pub async fn get_user(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    _user: RequireUser,
 ) -> AppResult<impl IntoResponse> {
     let user = Users::find_by_id(id)
         .one(&state.db)
@@ -91,10 +213,24 @@ pub async fn get_user(
 }
 
 /// PUT /api/users/:id
+#[utoipa::path(
+    put,
+    path = "/api/users/{id}",
+    params(("id" = Uuid, Path, description = "User id")),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "User updated", body = UserResponse),
+        (status = 401, description = "Missing or invalid session", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse),
+    ),
+    tag = "users",
+)]
 pub async fn update_user(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
-    Json(payload): Json<UpdateUserRequest>,
+    _user: RequireUser,
+    ValidatedJson(payload): ValidatedJson<UpdateUserRequest>,
 ) -> AppResult<impl IntoResponse> {
     let user = Users::find_by_id(id)
         .one(&state.db)
@@ -120,9 +256,21 @@ pub async fn update_user(
 }
 
 /// DELETE /api/users/:id
+#[utoipa::path(
+    delete,
+    path = "/api/users/{id}",
+    params(("id" = Uuid, Path, description = "User id")),
+    responses(
+        (status = 204, description = "User deleted"),
+        (status = 401, description = "Missing or invalid session", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+    ),
+    tag = "users",
+)]
 pub async fn delete_user(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
+    _user: RequireUser,
 ) -> AppResult<impl IntoResponse> {
     let result = Users::delete_by_id(id)
         .exec(&state.db)
@@ -0,0 +1,32 @@
+use utoipa::OpenApi;
+
+use crate::error::ErrorResponse;
+use crate::modules::{health, users};
+
+/// Aggregates every documented path and schema into a single OpenAPI document,
+/// served as JSON from `/api-docs/openapi.json` and rendered by the Swagger UI
+/// mounted at `/docs`.
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        health::handler::root,
+        health::handler::health_check,
+        users::handler::create_user,
+        users::handler::list_users,
+        users::handler::get_user,
+        users::handler::update_user,
+        users::handler::delete_user,
+    ),
+    components(schemas(
+        users::handler::CreateUserRequest,
+        users::handler::UpdateUserRequest,
+        users::handler::UserResponse,
+        users::handler::UserPage,
+        ErrorResponse,
+    )),
+    tags(
+        (name = "health", description = "Service health and liveness"),
+        (name = "users", description = "User account management"),
+    ),
+)]
+pub struct ApiDoc;